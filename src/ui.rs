@@ -1,3 +1,5 @@
+mod key_map;
+mod sortable_table;
 mod tab_abnormality;
 mod tab_reader;
 mod tab_topic;
@@ -5,16 +7,16 @@ mod tab_writer;
 
 use crate::state::State;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     symbols::DOT,
-    widgets::{Block, Borders, Tabs},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap},
     Frame, Terminal,
 };
 use std::{
@@ -26,7 +28,10 @@ use std::{
 use tracing::error;
 
 use self::{
-    tab_abnormality::TabAbnormality, tab_reader::TabReader, tab_topic::TabTopic,
+    key_map::{Action, KeyMap},
+    tab_abnormality::TabAbnormality,
+    tab_reader::TabReader,
+    tab_topic::TabTopic,
     tab_writer::TabWriter,
 };
 
@@ -40,6 +45,25 @@ pub(crate) struct Tui {
     tick_dur: Duration,
     tab_index: usize,
     state: Arc<Mutex<State>>,
+    // Rects from the most recent `draw_ui` call, kept around so mouse events
+    // (which arrive between draws) can be hit-tested against the layout.
+    tabs_rect: Rect,
+    // One entry per visible pane (one when not split, two when split),
+    // indexed by pane number (0 = left/only pane, 1 = right pane).
+    content_rects: Vec<Rect>,
+    key_map: KeyMap,
+    // Split-pane state: when `split` is set, `chunks[1]` is divided in two
+    // and pane 1 shows `split_tab_index` instead of `tab_index`.
+    split: bool,
+    split_tab_index: usize,
+    focused_pane: usize,
+    // Filter state: `filtering` is true while the query is being edited
+    // (i.e. between pressing `/` and `Enter`/`Esc`); `filter_query` is
+    // applied to the Abnormalities tab regardless, so results narrow live.
+    filtering: bool,
+    filter_query: String,
+    // Whether the detail popup for the focused pane's selected row is open.
+    show_detail: bool,
 }
 
 impl Tui {
@@ -52,6 +76,33 @@ impl Tui {
             tab_topic: TabTopic::new(),
             tab_abnormality: TabAbnormality::new(),
             tab_reader: TabReader::new(),
+            tabs_rect: Rect::default(),
+            content_rects: Vec::new(),
+            key_map: Self::load_key_map(),
+            split: false,
+            // Defaults to Abnormalities, so splitting immediately lets an
+            // operator correlate a writer with its abnormalities.
+            split_tab_index: 3,
+            focused_pane: 0,
+            filtering: false,
+            filter_query: String::new(),
+            show_detail: false,
+        }
+    }
+
+    /// Load key bindings from the user's config file, falling back to the
+    /// built-in defaults if it's absent or fails to parse.
+    fn load_key_map() -> KeyMap {
+        let Some(path) = key_map::default_path() else {
+            return KeyMap::defaults();
+        };
+
+        match KeyMap::load(&path) {
+            Ok(key_map) => key_map,
+            Err(err) => {
+                error!("failed to load key bindings from {path:?}: {err}");
+                KeyMap::defaults()
+            }
         }
     }
 
@@ -112,53 +163,261 @@ impl Tui {
 
     fn process_events(&mut self, timeout: Duration) -> io::Result<ControlFlow<()>> {
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                use KeyCode as C;
+            match event::read()? {
+                Event::Key(key) if self.show_detail => {
+                    if self.key_map.resolve(key.code, key.modifiers) == Some(Action::DismissDetail)
+                    {
+                        self.show_detail = false;
+                    }
+                }
+                Event::Key(key) if self.filtering => {
+                    self.handle_filter_key(key.code);
+                }
+                Event::Key(key) => {
+                    let Some(action) = self.key_map.resolve(key.code, key.modifiers) else {
+                        return Ok(ControlFlow::Continue(()));
+                    };
 
-                let n_tabs = TAB_TITLES.len();
+                    let n_tabs = TAB_TITLES.len();
 
-                match key.code {
-                    C::Char('q') => return Ok(ControlFlow::Break(())),
-                    C::Up => {
-                        self.key_up();
-                    }
-                    C::Down => {
-                        self.key_down();
-                    }
-                    C::Left => {
-                        // *self.table_state.offset_mut() =
-                        //     self.table_state.offset().saturating_sub(1);
-                    }
-                    C::Right => {
-                        // *self.table_state.offset_mut() =
-                        //     self.table_state.offset().saturating_add(1);
-                    }
-                    C::PageUp => {
-                        self.key_page_up();
+                    match action {
+                        Action::Quit => return Ok(ControlFlow::Break(())),
+                        Action::ItemUp => {
+                            self.key_up();
+                        }
+                        Action::ItemDown => {
+                            self.key_down();
+                        }
+                        Action::PageUp => {
+                            self.key_page_up();
+                        }
+                        Action::PageDown => {
+                            self.key_page_down();
+                        }
+                        Action::First => {
+                            self.key_home();
+                        }
+                        Action::Last => {
+                            self.key_end();
+                        }
+                        Action::NextTab => {
+                            // Jump to next tab (in the focused pane)
+                            let idx = (self.active_tab_index() + 1) % n_tabs;
+                            self.set_active_tab_index(idx);
+                        }
+                        Action::PrevTab => {
+                            // Go to previous tab (in the focused pane)
+                            let idx = (self.active_tab_index() + (n_tabs - 1)) % n_tabs;
+                            self.set_active_tab_index(idx);
+                        }
+                        Action::ToggleSplit => {
+                            self.split = !self.split;
+                        }
+                        Action::FocusLeftPane => {
+                            if self.split {
+                                self.focused_pane = 0;
+                            }
+                        }
+                        Action::FocusRightPane => {
+                            if self.split {
+                                self.focused_pane = 1;
+                            }
+                        }
+                        Action::StartFilter => {
+                            self.filtering = true;
+                            self.filter_query.clear();
+                        }
+                        Action::CycleSortColumn => {
+                            self.cycle_sort_column();
+                        }
+                        Action::ToggleSortOrder => {
+                            self.toggle_sort_order();
+                        }
+                        Action::ShowDetail => {
+                            // Only open the popup if there's a selected row
+                            // with something to show; otherwise there'd be
+                            // nothing drawn and no way to tell the popup is
+                            // open except by its `Esc`-only key handling.
+                            if !self.selected_detail().is_empty() {
+                                self.show_detail = true;
+                            }
+                        }
+                        Action::DismissDetail => {
+                            // Handled above by the `self.show_detail` guard;
+                            // reachable here only when the popup is already
+                            // closed, in which case there's nothing to do.
+                        }
                     }
-                    C::PageDown => {
-                        self.key_page_down();
-                    }
-                    C::Home => {
-                        self.key_home();
-                    }
-                    C::End => {
-                        self.key_end();
-                    }
-                    C::Tab => {
-                        // Jump to next tab
-                        self.tab_index = (self.tab_index + 1) % n_tabs;
+                }
+                Event::Mouse(mouse) => {
+                    self.handle_mouse_event(mouse);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// The pane the focused, currently-active tab index belongs to: always
+    /// pane 0 outside of split mode.
+    fn active_pane(&self) -> usize {
+        if self.split {
+            self.focused_pane
+        } else {
+            0
+        }
+    }
+
+    fn tab_index_for_pane(&self, pane: usize) -> usize {
+        if pane == 1 {
+            self.split_tab_index
+        } else {
+            self.tab_index
+        }
+    }
+
+    fn set_tab_index_for_pane(&mut self, pane: usize, idx: usize) {
+        if pane == 1 {
+            self.split_tab_index = idx;
+        } else {
+            self.tab_index = idx;
+        }
+    }
+
+    fn active_tab_index(&self) -> usize {
+        self.tab_index_for_pane(self.active_pane())
+    }
+
+    fn set_active_tab_index(&mut self, idx: usize) {
+        let pane = self.active_pane();
+        self.set_tab_index_for_pane(pane, idx);
+    }
+
+    fn cycle_sort_column(&mut self) {
+        match self.active_tab_index() {
+            0 => self.tab_writer.cycle_sort_column(),
+            1 => self.tab_reader.cycle_sort_column(),
+            2 => self.tab_topic.cycle_sort_column(),
+            3 => self.tab_abnormality.cycle_sort_column(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn toggle_sort_order(&mut self) {
+        match self.active_tab_index() {
+            0 => self.tab_writer.toggle_sort_order(),
+            1 => self.tab_reader.toggle_sort_order(),
+            2 => self.tab_topic.toggle_sort_order(),
+            3 => self.tab_abnormality.toggle_sort_order(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn selected_detail(&self) -> Vec<(String, String)> {
+        match self.active_tab_index() {
+            0 => self.tab_writer.selected_detail(),
+            1 => self.tab_reader.selected_detail(),
+            2 => self.tab_topic.selected_detail(),
+            3 => self.tab_abnormality.selected_detail(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Advance the filter-editing state machine entered via `/`: characters
+    /// append, backspace removes, Enter commits the query and stops editing,
+    /// Esc clears the query and stops editing.
+    fn handle_filter_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => {
+                self.filter_query.clear();
+                self.filtering = false;
+            }
+            KeyCode::Enter => {
+                self.filtering = false;
+            }
+            KeyCode::Backspace => {
+                self.filter_query.pop();
+            }
+            KeyCode::Char(c) => {
+                self.filter_query.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_mouse_event(&mut self, mouse: event::MouseEvent) {
+        let (column, row) = (mouse.column, mouse.row);
+
+        match mouse.kind {
+            MouseEventKind::Down(_) => {
+                if self.tabs_rect.intersects(Rect::new(column, row, 1, 1)) {
+                    if let Some(tab_index) = self.tab_at(column, row) {
+                        self.set_active_tab_index(tab_index);
                     }
-                    C::BackTab => {
-                        // Go to previous tab
-                        self.tab_index = (self.tab_index + (n_tabs - 1)) % n_tabs;
+                } else if let Some(pane) = self.pane_at(column, row) {
+                    if self.split {
+                        self.focused_pane = pane;
                     }
-                    _ => {}
+                    self.click_content(pane, column, row);
                 }
             }
+            MouseEventKind::ScrollDown => self.key_down(),
+            MouseEventKind::ScrollUp => self.key_up(),
+            _ => {}
         }
+    }
 
-        Ok(ControlFlow::Continue(()))
+    /// Which rendered pane, if any, a `(column, row)` mouse position falls in.
+    fn pane_at(&self, column: u16, row: u16) -> Option<usize> {
+        self.content_rects
+            .iter()
+            .position(|rect| rect.intersects(Rect::new(column, row, 1, 1)))
+    }
+
+    /// Hit-test a `(column, row)` mouse position against the titles rendered
+    /// by the `Tabs` widget in `tabs_rect`, returning the tab index clicked.
+    fn tab_at(&self, column: u16, row: u16) -> Option<usize> {
+        if row != self.tabs_rect.y + 1 {
+            return None;
+        }
+
+        // Start just inside the block border; each title's width below
+        // includes the one-cell padding `Tabs` renders on either side of it.
+        let mut x = self.tabs_rect.x + 1;
+        for (idx, title) in TAB_TITLES.iter().enumerate() {
+            let width = title.chars().count() as u16 + 2;
+            if column >= x && column < x + width {
+                return Some(idx);
+            }
+            // `+ 1` for the divider between consecutive tab titles.
+            x += width + 1;
+        }
+
+        None
+    }
+
+    /// Translate a click inside `content_rects[pane]` into a row selection on
+    /// that pane's tab.
+    fn click_content(&mut self, pane: usize, column: u16, row: u16) {
+        let rect = self.content_rects[pane];
+        // In split mode each pane is wrapped in an extra focus-indicator
+        // border on top of the tab's own block border.
+        let extra_border = if self.split { 1 } else { 0 };
+
+        // Account for the block border(s) and the header row.
+        let Some(local_row) = row.checked_sub(rect.y + 2 + extra_border) else {
+            return;
+        };
+        let local_column = column.saturating_sub(rect.x + 1 + extra_border);
+
+        match self.tab_index_for_pane(pane) {
+            0 => self.tab_writer.on_click(local_column, local_row),
+            1 => self.tab_reader.on_click(local_column, local_row),
+            2 => self.tab_topic.on_click(local_column, local_row),
+            3 => self.tab_abnormality.on_click(local_column, local_row),
+            _ => unreachable!(),
+        }
     }
 
     fn draw_ui<B>(&mut self, frame: &mut Frame<B>, _elapsed_time: Duration)
@@ -179,6 +438,10 @@ impl Tui {
             .constraints([Constraint::Percentage(20), Constraint::Percentage(80)].as_ref())
             .split(frame.size());
 
+        // Remember the rendered layout so mouse events can be hit-tested
+        // against it between draws.
+        self.tabs_rect = chunks[0];
+
         // Build the container for tabs
         let tabs_block = Block::default().title("Tabs").borders(Borders::ALL);
         let tabs = Tabs::new(TAB_TITLES.to_vec())
@@ -186,21 +449,108 @@ impl Tui {
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().fg(Color::Yellow))
             .divider(DOT)
-            .select(self.tab_index);
+            // Highlight the active pane's tab, not always the left pane's,
+            // so the tab bar reflects focus once it moves to the right pane.
+            .select(self.active_tab_index());
         frame.render_widget(tabs, chunks[0]);
 
-        // Render the tab content according to the current tab index.
-        match self.tab_index {
-            0 => self.tab_writer.render(&state, frame, chunks[1]),
-            1 => self.tab_reader.render(&state, frame, chunks[1]),
-            2 => self.tab_topic.render(&state, frame, chunks[1]),
-            3 => self.tab_abnormality.render(&state, frame, chunks[1]),
-            _ => unreachable!(),
+        // Reserve a one-line prompt below the table while a filter query is
+        // being edited or applied.
+        let (content_area, prompt_area) = if self.filtering || !self.filter_query.is_empty() {
+            let areas = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+                .split(chunks[1]);
+            (areas[0], Some(areas[1]))
+        } else {
+            (chunks[1], None)
+        };
+
+        if self.split {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                .split(content_area);
+            self.content_rects = panes.to_vec();
+
+            // Draw each pane's focus-indicator border directly (rather than
+            // through a `&mut self` method) so the two calls below don't
+            // conflict with the `state` lock guard they borrow.
+            let left = pane_border(frame, panes[0], self.focused_pane == 0);
+            match self.tab_index {
+                0 => self.tab_writer.render(&state, frame, left),
+                1 => self.tab_reader.render(&state, frame, left),
+                2 => self.tab_topic.render(&state, frame, left),
+                3 => self
+                    .tab_abnormality
+                    .render(&state, frame, left, &self.filter_query),
+                _ => unreachable!(),
+            }
+
+            let right = pane_border(frame, panes[1], self.focused_pane == 1);
+            match self.split_tab_index {
+                0 => self.tab_writer.render(&state, frame, right),
+                1 => self.tab_reader.render(&state, frame, right),
+                2 => self.tab_topic.render(&state, frame, right),
+                3 => self
+                    .tab_abnormality
+                    .render(&state, frame, right, &self.filter_query),
+                _ => unreachable!(),
+            }
+        } else {
+            self.content_rects = vec![content_area];
+
+            // Render the tab content according to the current tab index.
+            match self.tab_index {
+                0 => self.tab_writer.render(&state, frame, content_area),
+                1 => self.tab_reader.render(&state, frame, content_area),
+                2 => self.tab_topic.render(&state, frame, content_area),
+                3 => self
+                    .tab_abnormality
+                    .render(&state, frame, content_area, &self.filter_query),
+                _ => unreachable!(),
+            }
+        }
+
+        if let Some(prompt_area) = prompt_area {
+            let prompt = Paragraph::new(format!("/{}", self.filter_query));
+            frame.render_widget(prompt, prompt_area);
+        }
+
+        if self.show_detail {
+            self.render_detail_popup(frame, frame.size());
         }
     }
 
+    /// Render a centered detail overlay for the focused pane's selected row,
+    /// after everything else so it draws on top.
+    fn render_detail_popup<B>(&mut self, frame: &mut Frame<B>, area: Rect)
+    where
+        B: Backend,
+    {
+        let fields = self.selected_detail();
+        if fields.is_empty() {
+            return;
+        }
+
+        let popup_area = centered_rect(70, 60, area);
+        frame.render_widget(Clear, popup_area);
+
+        let text = fields
+            .into_iter()
+            .map(|(field, value)| format!("{field}: {value}"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let block = Block::default().title("Detail").borders(Borders::ALL);
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        frame.render_widget(paragraph, popup_area);
+    }
+
     fn key_up(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.previous_item(),
             1 => self.tab_reader.previous_item(),
             2 => self.tab_topic.previous_item(),
@@ -210,7 +560,7 @@ impl Tui {
     }
 
     fn key_down(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.next_item(),
             1 => self.tab_reader.next_item(),
             2 => self.tab_topic.next_item(),
@@ -220,7 +570,7 @@ impl Tui {
     }
 
     fn key_page_up(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.previous_page(),
             1 => self.tab_reader.previous_page(),
             2 => self.tab_topic.previous_page(),
@@ -230,7 +580,7 @@ impl Tui {
     }
 
     fn key_page_down(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.next_page(),
             1 => self.tab_reader.next_page(),
             2 => self.tab_topic.next_page(),
@@ -240,7 +590,7 @@ impl Tui {
     }
 
     fn key_home(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.first_item(),
             1 => self.tab_reader.first_item(),
             2 => self.tab_topic.first_item(),
@@ -250,7 +600,7 @@ impl Tui {
     }
 
     fn key_end(&mut self) {
-        match self.tab_index {
+        match self.active_tab_index() {
             0 => self.tab_writer.last_item(),
             1 => self.tab_reader.last_item(),
             2 => self.tab_topic.last_item(),
@@ -259,3 +609,50 @@ impl Tui {
         }
     }
 }
+
+/// Draw a split-pane's focus-indicator border and return the inner `Rect`
+/// its tab content should render into. A free function (not a `Tui` method)
+/// so it doesn't need to borrow `self`.
+fn pane_border<B>(frame: &mut Frame<B>, rect: Rect, focused: bool) -> Rect
+where
+    B: Backend,
+{
+    let border_style = if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let inner = block.inner(rect);
+    frame.render_widget(block, rect);
+    inner
+}
+
+/// A `Rect` of `percent_x` by `percent_y` centered within `area`.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_y) / 2),
+                Constraint::Percentage(percent_y),
+                Constraint::Percentage((100 - percent_y) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage((100 - percent_x) / 2),
+                Constraint::Percentage(percent_x),
+                Constraint::Percentage((100 - percent_x) / 2),
+            ]
+            .as_ref(),
+        )
+        .split(vertical[1])[1]
+}
@@ -0,0 +1,215 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A named action the TUI can perform, independent of which physical key
+/// triggers it. `process_events` resolves an incoming `KeyEvent` into one of
+/// these through the active [`KeyMap`] before dispatching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Action {
+    Quit,
+    NextTab,
+    PrevTab,
+    ItemUp,
+    ItemDown,
+    PageUp,
+    PageDown,
+    First,
+    Last,
+    ToggleSplit,
+    FocusLeftPane,
+    FocusRightPane,
+    StartFilter,
+    CycleSortColumn,
+    ToggleSortOrder,
+    ShowDetail,
+    DismissDetail,
+}
+
+impl Action {
+    /// The config key used to name this action in the key-binding file.
+    fn config_key(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::NextTab => "next_tab",
+            Action::PrevTab => "prev_tab",
+            Action::ItemUp => "item_up",
+            Action::ItemDown => "item_down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::First => "first",
+            Action::Last => "last",
+            Action::ToggleSplit => "toggle_split",
+            Action::FocusLeftPane => "focus_left_pane",
+            Action::FocusRightPane => "focus_right_pane",
+            Action::StartFilter => "start_filter",
+            Action::CycleSortColumn => "cycle_sort_column",
+            Action::ToggleSortOrder => "toggle_sort_order",
+            Action::ShowDetail => "show_detail",
+            Action::DismissDetail => "dismiss_detail",
+        }
+    }
+
+    const ALL: &'static [Action] = &[
+        Action::Quit,
+        Action::NextTab,
+        Action::PrevTab,
+        Action::ItemUp,
+        Action::ItemDown,
+        Action::PageUp,
+        Action::PageDown,
+        Action::First,
+        Action::Last,
+        Action::ToggleSplit,
+        Action::FocusLeftPane,
+        Action::FocusRightPane,
+        Action::StartFilter,
+        Action::CycleSortColumn,
+        Action::ToggleSortOrder,
+        Action::ShowDetail,
+        Action::DismissDetail,
+    ];
+}
+
+/// Maps `(KeyCode, KeyModifiers)` combinations to [`Action`]s, so key
+/// bindings can be customized at runtime instead of being hardcoded in
+/// `process_events`.
+pub(crate) struct KeyMap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl KeyMap {
+    /// The built-in bindings: the keys `process_events` handled before this
+    /// config system existed, plus keys for the split-pane, filter, sort and
+    /// detail-popup actions.
+    pub(crate) fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+        let mut bind = |code: KeyCode, modifiers: KeyModifiers, action: Action| {
+            bindings.insert((code, modifiers), action);
+        };
+
+        bind(KeyCode::Char('q'), KeyModifiers::NONE, Action::Quit);
+        bind(KeyCode::Tab, KeyModifiers::NONE, Action::NextTab);
+        bind(KeyCode::BackTab, KeyModifiers::NONE, Action::PrevTab);
+        bind(KeyCode::Up, KeyModifiers::NONE, Action::ItemUp);
+        bind(KeyCode::Down, KeyModifiers::NONE, Action::ItemDown);
+        bind(KeyCode::PageUp, KeyModifiers::NONE, Action::PageUp);
+        bind(KeyCode::PageDown, KeyModifiers::NONE, Action::PageDown);
+        bind(KeyCode::Home, KeyModifiers::NONE, Action::First);
+        bind(KeyCode::End, KeyModifiers::NONE, Action::Last);
+        bind(KeyCode::Char('s'), KeyModifiers::NONE, Action::ToggleSplit);
+        // Left/Right were previously unused placeholders; repurpose them to
+        // move focus between the two panes when split mode is active.
+        bind(KeyCode::Left, KeyModifiers::NONE, Action::FocusLeftPane);
+        bind(KeyCode::Right, KeyModifiers::NONE, Action::FocusRightPane);
+        bind(KeyCode::Char('/'), KeyModifiers::NONE, Action::StartFilter);
+        bind(KeyCode::Char('c'), KeyModifiers::NONE, Action::CycleSortColumn);
+        bind(KeyCode::Char('o'), KeyModifiers::NONE, Action::ToggleSortOrder);
+        bind(KeyCode::Enter, KeyModifiers::NONE, Action::ShowDetail);
+        bind(KeyCode::Esc, KeyModifiers::NONE, Action::DismissDetail);
+
+        Self { bindings }
+    }
+
+    /// Load a key-binding config from `path`, falling back to [`Self::defaults`]
+    /// for any action the file doesn't mention. The file is TOML, e.g.:
+    ///
+    /// ```toml
+    /// item_down = ["Down", "j"]
+    /// item_up = ["Up", "k"]
+    /// ```
+    pub(crate) fn load(path: &Path) -> io::Result<Self> {
+        let mut key_map = Self::defaults();
+
+        if !path.exists() {
+            return Ok(key_map);
+        }
+
+        let text = fs::read_to_string(path)?;
+        let config: RawKeyMapConfig = toml::from_str(&text)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        for action in Action::ALL {
+            let Some(bindings) = config.0.get(action.config_key()) else {
+                continue;
+            };
+
+            // An action explicitly listed in the config replaces, rather
+            // than extends, the default bindings for that action.
+            key_map
+                .bindings
+                .retain(|_, bound_action| bound_action != action);
+
+            for binding in bindings {
+                let Some((code, modifiers)) = parse_binding(binding) else {
+                    tracing::warn!("ignoring unrecognized key binding {binding:?}");
+                    continue;
+                };
+                key_map.bindings.insert((code, modifiers), *action);
+            }
+        }
+
+        Ok(key_map)
+    }
+
+    /// Resolve a pressed key into the [`Action`] it's bound to, if any.
+    pub(crate) fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RawKeyMapConfig(HashMap<String, Vec<String>>);
+
+/// Parse a binding such as `"q"`, `"ctrl+c"`, or `"PageDown"` into a
+/// `(KeyCode, KeyModifiers)` pair.
+fn parse_binding(binding: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut code = None;
+
+    for part in binding.split('+') {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            key => code = Some(parse_key_code(key)?),
+        }
+    }
+
+    Some((code?, modifiers))
+}
+
+/// The conventional location for the user's key-binding overrides:
+/// `$XDG_CONFIG_HOME/ddshark/keymap.toml`, falling back to `~/.config`.
+pub(crate) fn default_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("ddshark").join("keymap.toml"))
+}
+
+fn parse_key_code(key: &str) -> Option<KeyCode> {
+    let code = match key {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        single if single.chars().count() == 1 => KeyCode::Char(single.chars().next().unwrap()),
+        _ => return None,
+    };
+    Some(code)
+}
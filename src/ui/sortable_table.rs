@@ -0,0 +1,171 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    widgets::{Cell, TableState},
+};
+
+/// Shared cursor, paging, and column-sort bookkeeping for the tab tables
+/// (Writers, Readers, Topics, Abnormalities). Each tab builds its own rows
+/// from domain state as `Vec<String>` and hands them to [`Self::sort_rows`];
+/// this only owns the mechanical bookkeeping that would otherwise be
+/// hand-copied across every tab.
+pub(crate) struct SortableTable {
+    table_state: TableState,
+    num_entries: usize,
+    num_columns: usize,
+    sort_column: usize,
+    sort_ascending: bool,
+    // The rows as last rendered (post-filter, post-sort), kept around so
+    // `selected_detail` can look up the currently selected one.
+    displayed_rows: Vec<Vec<String>>,
+}
+
+impl SortableTable {
+    /// `sort_ascending` sets the initial sort direction for column 0, the
+    /// default active sort column.
+    pub(crate) fn new(num_columns: usize, sort_ascending: bool) -> Self {
+        Self {
+            table_state: TableState::default(),
+            num_entries: 0,
+            num_columns,
+            sort_column: 0,
+            sort_ascending,
+            displayed_rows: Vec::new(),
+        }
+    }
+
+    pub(crate) fn state_mut(&mut self) -> &mut TableState {
+        &mut self.table_state
+    }
+
+    /// Sort `rows` (one `Vec<String>` per row, in the same column order as
+    /// the table's header) in place by the active sort column and direction.
+    pub(crate) fn sort_rows(&self, rows: &mut [Vec<String>]) {
+        rows.sort_unstable_by(|lhs, rhs| {
+            let ordering = lhs[self.sort_column].cmp(&rhs[self.sort_column]);
+            if self.sort_ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+    }
+
+    /// The header cell for column `idx`, marked with a sort-direction arrow
+    /// if it's the active sort column.
+    pub(crate) fn header_cell(&self, idx: usize, title: &str) -> Cell<'static> {
+        if idx == self.sort_column {
+            let arrow = if self.sort_ascending { '▲' } else { '▼' };
+            Cell::from(format!("{title} {arrow}"))
+                .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Yellow))
+        } else {
+            Cell::from(title.to_string())
+        }
+    }
+
+    pub(crate) fn set_num_entries(&mut self, num_entries: usize) {
+        self.num_entries = num_entries;
+    }
+
+    /// Remember the rows as rendered, so `selected_detail` can look one up.
+    pub(crate) fn set_displayed_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.displayed_rows = rows;
+    }
+
+    /// All fields of the currently selected row, for the detail popup.
+    pub(crate) fn selected_detail(&self, titles: &[&str]) -> Vec<(String, String)> {
+        let Some(idx) = self.table_state.selected() else {
+            return Vec::new();
+        };
+        let Some(row) = self.displayed_rows.get(idx) else {
+            return Vec::new();
+        };
+
+        titles
+            .iter()
+            .zip(row.iter())
+            .map(|(title, value)| (title.to_string(), value.clone()))
+            .collect()
+    }
+
+    /// Cycle the active sort column through the table's columns in order.
+    pub(crate) fn cycle_sort_column(&mut self) {
+        self.sort_column = (self.sort_column + 1) % self.num_columns;
+    }
+
+    /// Toggle between ascending and descending order for the active column.
+    pub(crate) fn toggle_sort_order(&mut self) {
+        self.sort_ascending = !self.sort_ascending;
+    }
+
+    /// Handle a mouse click at `(column, row)`, relative to the table body
+    /// (i.e. with the surrounding block border and header already removed).
+    pub(crate) fn on_click(&mut self, _column: u16, row: u16) {
+        self.select_at(row);
+    }
+
+    /// Select the row at `row` within the currently rendered viewport,
+    /// i.e. the row offset the user clicked on, relative to the table body.
+    fn select_at(&mut self, row: u16) {
+        if self.num_entries == 0 {
+            return;
+        }
+        let idx = (self.table_state.offset() + row as usize).min(self.num_entries - 1);
+        self.table_state.select(Some(idx));
+    }
+
+    pub(crate) fn previous_item(&mut self) {
+        if self.num_entries > 0 {
+            let new_idx = match self.table_state.selected() {
+                Some(idx) => idx.saturating_sub(1),
+                None => 0,
+            };
+            self.table_state.select(Some(new_idx));
+        }
+    }
+
+    pub(crate) fn next_item(&mut self) {
+        if let Some(last_idx) = self.num_entries.checked_sub(1) {
+            let new_idx = match self.table_state.selected() {
+                Some(idx) => idx.saturating_add(1).min(last_idx),
+                None => 0,
+            };
+            self.table_state.select(Some(new_idx));
+        }
+    }
+
+    pub(crate) fn previous_page(&mut self) {
+        if self.num_entries > 0 {
+            let new_idx = match self.table_state.selected() {
+                Some(idx) => idx.saturating_sub(30),
+                None => 0,
+            };
+            self.table_state.select(Some(new_idx));
+        }
+    }
+
+    pub(crate) fn next_page(&mut self) {
+        if let Some(last_idx) = self.num_entries.checked_sub(1) {
+            let new_idx = match self.table_state.selected() {
+                Some(idx) => idx.saturating_add(30).min(last_idx),
+                None => 0,
+            };
+            self.table_state.select(Some(new_idx));
+        }
+    }
+
+    pub(crate) fn first_item(&mut self) {
+        if self.num_entries > 0 {
+            self.table_state.select(Some(0));
+        }
+    }
+
+    pub(crate) fn last_item(&mut self) {
+        if let Some(idx) = self.num_entries.checked_sub(1) {
+            self.table_state.select(Some(idx));
+        }
+    }
+
+    pub(crate) fn selected(&self) -> Option<usize> {
+        self.table_state.selected()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     state::{Abnormality, State},
+    ui::sortable_table::SortableTable,
     utils::GUIDExt,
 };
 use ratatui::{
@@ -7,44 +8,37 @@ use ratatui::{
     layout::Constraint,
     prelude::Rect,
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Row, Table, TableState},
+    widgets::{Block, Borders, Row, Table},
     Frame,
 };
 use rustdds::GUID;
 
+const TITLES: [&str; 5] = ["when", "writer", "reader", "topic", "desc"];
+
 pub(crate) struct TabAbnormality {
-    table_state: TableState,
-    num_entries: usize,
+    table: SortableTable,
 }
 impl TabAbnormality {
     pub(crate) fn new() -> Self {
         Self {
-            table_state: TableState::default(),
-            num_entries: 0,
+            // Matches the previous hardcoded behavior: sort by `when`,
+            // descending (most recent first).
+            table: SortableTable::new(TITLES.len(), false),
         }
     }
 
-    pub(crate) fn render<B>(&mut self, state: &State, frame: &mut Frame<B>, rect: Rect)
+    pub(crate) fn render<B>(&mut self, state: &State, frame: &mut Frame<B>, rect: Rect, filter: &str)
     where
         B: Backend,
     {
-        const TITLE_WHEN: &str = "when";
-        const TITLE_WRITER_ID: &str = "writer";
-        const TITLE_READER_ID: &str = "reader";
-        const TITLE_TOPIC_NAME: &str = "topic";
-        const TITLE_DESC: &str = "desc";
-
         let mut abnormalities: Vec<_> = state.abnormalities.iter().collect();
-        abnormalities.sort_unstable_by(|lhs, rhs| lhs.when.cmp(&rhs.when).reverse());
-
-        let header = vec![
-            TITLE_WHEN,
-            TITLE_WRITER_ID,
-            TITLE_READER_ID,
-            TITLE_TOPIC_NAME,
-            TITLE_DESC,
-        ];
-        let rows: Vec<_> = abnormalities
+
+        if !filter.is_empty() {
+            let needle = filter.to_lowercase();
+            abnormalities.retain(|report| abnormality_matches(report, &needle));
+        }
+
+        let mut rows: Vec<_> = abnormalities
             .into_iter()
             .map(|report| {
                 let Abnormality {
@@ -71,7 +65,11 @@ impl TabAbnormality {
             })
             .collect();
 
-        let widths: Vec<_> = header
+        self.table.sort_rows(&mut rows);
+        self.table.set_num_entries(rows.len());
+        self.table.set_displayed_rows(rows.clone());
+
+        let widths: Vec<_> = TITLES
             .iter()
             .enumerate()
             .map(|(idx, title)| {
@@ -85,12 +83,15 @@ impl TabAbnormality {
             })
             .collect();
 
-        let header = Row::new(header);
+        let header = Row::new(
+            TITLES
+                .iter()
+                .enumerate()
+                .map(|(idx, title)| self.table.header_cell(idx, title))
+                .collect::<Vec<_>>(),
+        );
         let rows: Vec<_> = rows.into_iter().map(Row::new).collect();
 
-        // Save the # of entires
-        self.num_entries = rows.len();
-
         let table_block = Block::default()
             .title("Abnormalities")
             .borders(Borders::ALL);
@@ -103,58 +104,66 @@ impl TabAbnormality {
             .highlight_style(Style::default().add_modifier(Modifier::BOLD))
             .highlight_symbol(">");
 
-        frame.render_stateful_widget(table, rect, &mut self.table_state);
+        frame.render_stateful_widget(table, rect, self.table.state_mut());
+    }
+
+    /// Handle a mouse click at `(column, row)`, relative to the table body
+    /// (i.e. with the surrounding block border and header already removed).
+    pub(crate) fn on_click(&mut self, column: u16, row: u16) {
+        self.table.on_click(column, row);
     }
 
     pub(crate) fn previous_item(&mut self) {
-        if self.num_entries > 0 {
-            let new_idx = match self.table_state.selected() {
-                Some(idx) => idx.saturating_sub(1),
-                None => 0,
-            };
-            self.table_state.select(Some(new_idx));
-        }
+        self.table.previous_item();
     }
 
     pub(crate) fn next_item(&mut self) {
-        if let Some(last_idx) = self.num_entries.checked_sub(1) {
-            let new_idx = match self.table_state.selected() {
-                Some(idx) => idx.saturating_add(1).min(last_idx),
-                None => 0,
-            };
-            self.table_state.select(Some(new_idx));
-        }
+        self.table.next_item();
     }
 
     pub(crate) fn previous_page(&mut self) {
-        if self.num_entries > 0 {
-            let new_idx = match self.table_state.selected() {
-                Some(idx) => idx.saturating_sub(30),
-                None => 0,
-            };
-            self.table_state.select(Some(new_idx));
-        }
+        self.table.previous_page();
     }
 
     pub(crate) fn next_page(&mut self) {
-        if let Some(last_idx) = self.num_entries.checked_sub(1) {
-            let new_idx = match self.table_state.selected() {
-                Some(idx) => idx.saturating_add(30).min(last_idx),
-                None => 0,
-            };
-            self.table_state.select(Some(new_idx));
-        }
+        self.table.next_page();
     }
 
     pub(crate) fn first_item(&mut self) {
-        if self.num_entries > 0 {
-            self.table_state.select(Some(0));
-        }
+        self.table.first_item();
     }
 
     pub(crate) fn last_item(&mut self) {
-        if let Some(idx) = self.num_entries.checked_sub(1) {
-            self.table_state.select(Some(idx));
-        }
+        self.table.last_item();
     }
+
+    pub(crate) fn cycle_sort_column(&mut self) {
+        self.table.cycle_sort_column();
+    }
+
+    pub(crate) fn toggle_sort_order(&mut self) {
+        self.table.toggle_sort_order();
+    }
+
+    /// All fields of the currently selected row, for the detail popup.
+    pub(crate) fn selected_detail(&self) -> Vec<(String, String)> {
+        self.table.selected_detail(&TITLES)
+    }
+}
+
+/// Whether `report` matches `needle` (already lowercased) as a
+/// case-insensitive substring of its writer, reader, topic, or description.
+fn abnormality_matches(report: &Abnormality, needle: &str) -> bool {
+    let guid_matches = |guid: Option<GUID>| {
+        guid.map(|guid| guid.display().to_string().to_lowercase().contains(needle))
+            .unwrap_or(false)
+    };
+
+    guid_matches(report.writer_id)
+        || guid_matches(report.reader_id)
+        || report
+            .topic_name
+            .as_deref()
+            .is_some_and(|topic_name| topic_name.to_lowercase().contains(needle))
+        || report.desc.to_lowercase().contains(needle)
 }
@@ -0,0 +1,131 @@
+use crate::{
+    state::{State, Topic},
+    ui::sortable_table::SortableTable,
+};
+use ratatui::{
+    backend::Backend,
+    layout::Constraint,
+    prelude::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Row, Table},
+    Frame,
+};
+
+/// Column titles, in the same order as the rows built by `render`.
+const TITLES: [&str; 2] = ["name", "type"];
+
+pub(crate) struct TabTopic {
+    table: SortableTable,
+}
+
+impl TabTopic {
+    pub(crate) fn new() -> Self {
+        Self {
+            table: SortableTable::new(TITLES.len(), true),
+        }
+    }
+
+    pub(crate) fn render<B>(&mut self, state: &State, frame: &mut Frame<B>, rect: Rect)
+    where
+        B: Backend,
+    {
+        let topics: Vec<_> = state.topics.iter().collect();
+
+        let mut rows: Vec<_> = topics
+            .into_iter()
+            .map(|topic| {
+                let Topic {
+                    ref name,
+                    ref type_name,
+                } = *topic;
+
+                let name = name.clone();
+                let type_name = type_name.to_owned().unwrap_or_else(|| "<none>".to_string());
+
+                vec![name, type_name]
+            })
+            .collect();
+
+        self.table.sort_rows(&mut rows);
+        self.table.set_num_entries(rows.len());
+        self.table.set_displayed_rows(rows.clone());
+
+        let widths: Vec<_> = TITLES
+            .iter()
+            .enumerate()
+            .map(|(idx, title)| {
+                let max_len = rows
+                    .iter()
+                    .map(|row| row[idx].len())
+                    .max()
+                    .unwrap_or(0)
+                    .max(title.len());
+                Constraint::Max(max_len as u16)
+            })
+            .collect();
+
+        let header = Row::new(
+            TITLES
+                .iter()
+                .enumerate()
+                .map(|(idx, title)| self.table.header_cell(idx, title))
+                .collect::<Vec<_>>(),
+        );
+        let rows: Vec<_> = rows.into_iter().map(Row::new).collect();
+
+        let table_block = Block::default().title("Topics").borders(Borders::ALL);
+        let table = Table::new(rows)
+            .style(Style::default().fg(Color::White))
+            .header(header)
+            .block(table_block)
+            .widths(&widths)
+            .column_spacing(1)
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol(">");
+
+        frame.render_stateful_widget(table, rect, self.table.state_mut());
+    }
+
+    /// Handle a mouse click at `(column, row)`, relative to the table body
+    /// (i.e. with the surrounding block border and header already removed).
+    pub(crate) fn on_click(&mut self, column: u16, row: u16) {
+        self.table.on_click(column, row);
+    }
+
+    pub(crate) fn previous_item(&mut self) {
+        self.table.previous_item();
+    }
+
+    pub(crate) fn next_item(&mut self) {
+        self.table.next_item();
+    }
+
+    pub(crate) fn previous_page(&mut self) {
+        self.table.previous_page();
+    }
+
+    pub(crate) fn next_page(&mut self) {
+        self.table.next_page();
+    }
+
+    pub(crate) fn first_item(&mut self) {
+        self.table.first_item();
+    }
+
+    pub(crate) fn last_item(&mut self) {
+        self.table.last_item();
+    }
+
+    pub(crate) fn cycle_sort_column(&mut self) {
+        self.table.cycle_sort_column();
+    }
+
+    pub(crate) fn toggle_sort_order(&mut self) {
+        self.table.toggle_sort_order();
+    }
+
+    /// All fields of the currently selected row, for the detail popup.
+    pub(crate) fn selected_detail(&self) -> Vec<(String, String)> {
+        self.table.selected_detail(&TITLES)
+    }
+}